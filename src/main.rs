@@ -1,4 +1,6 @@
 use rand::{seq::SliceRandom, Rng, thread_rng };
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::io::{self, stdin, stdout, Write};
 use std::process::Command;
 use std::sync::{Arc, Mutex};
@@ -12,8 +14,87 @@ use termion::input::TermRead;
 use termion::raw::IntoRawMode;
 
 enum GameResult {
-    Won,
-    Lose
+    Won { attempts: usize, duration: Duration, summary: String },
+    Lose { summary: String }
+}
+
+// Running tally across the rounds of a single session.
+struct Scoreboard {
+    rounds: usize,
+    wins: usize,
+    total_attempts: usize,
+    fastest: Option<Duration>,
+}
+
+impl Scoreboard {
+    fn new() -> Self {
+        Scoreboard { rounds: 0, wins: 0, total_attempts: 0, fastest: None }
+    }
+
+    fn record(&mut self, result: &GameResult) {
+        self.rounds += 1;
+        if let GameResult::Won { attempts, duration, .. } = result {
+            self.wins += 1;
+            self.total_attempts += attempts;
+            self.fastest = Some(match self.fastest {
+                Some(best) if best <= *duration => best,
+                _ => *duration,
+            });
+        }
+    }
+
+    fn report(&self) {
+        println!("\n── Scoreboard ──");
+        println!("Rounds played : {}", self.rounds);
+        println!("Wins          : {}", self.wins);
+        println!("Total attempts: {}", self.total_attempts);
+        match self.fastest {
+            Some(best) => println!("Fastest solve : {:.1}s", best.as_secs_f64()),
+            None => println!("Fastest solve : —"),
+        }
+    }
+}
+
+// Randomness used by `Game::new`, pulled behind a trait so a round can be
+// driven by a deterministic generator for replay and testing.
+trait RangeRng {
+    fn gen_range(&mut self, range: std::ops::RangeInclusive<usize>) -> usize;
+    fn shuffle(&mut self, slice: &mut [u32]);
+}
+
+// Default, non-reproducible source backed by `thread_rng`.
+struct ThreadRangeRng;
+
+impl RangeRng for ThreadRangeRng {
+    fn gen_range(&mut self, range: std::ops::RangeInclusive<usize>) -> usize {
+        thread_rng().gen_range(range)
+    }
+
+    fn shuffle(&mut self, slice: &mut [u32]) {
+        slice.shuffle(&mut thread_rng());
+    }
+}
+
+// Deterministic source seeded from a `u64`, so the same seed always yields the
+// same secret and digit placement.
+struct SeededRng {
+    inner: StdRng,
+}
+
+impl SeededRng {
+    fn new(seed: u64) -> Self {
+        SeededRng { inner: StdRng::seed_from_u64(seed) }
+    }
+}
+
+impl RangeRng for SeededRng {
+    fn gen_range(&mut self, range: std::ops::RangeInclusive<usize>) -> usize {
+        self.inner.gen_range(range)
+    }
+
+    fn shuffle(&mut self, slice: &mut [u32]) {
+        slice.shuffle(&mut self.inner);
+    }
 }
 
 struct Game {
@@ -23,18 +104,29 @@ struct Game {
 
 const COUNTDOWN_SECONDS: u64 = 10;
 
+// Once the assist has narrowed the pool to this few, reveal one safe digit.
+const ASSIST_REVEAL_THRESHOLD: usize = 3;
+
 impl Game {
-    fn new(level: usize) -> Result<Self, String> {
-        if level < 3 || level > 9 {
+    // Single source of truth for the accepted level range, shared by both the
+    // guesser and codebreaker entry points.
+    fn validate_level(level: usize) -> Result<(), String> {
+        if !(3..=9).contains(&level) {
             return Err("Invalid level. The level should be between 3 and 9.".to_string());
         }
+        Ok(())
+    }
+
+    fn new(level: usize, rng: &mut dyn RangeRng) -> Result<Self, String> {
+        Game::validate_level(level)?;
 
-        let mut rng = thread_rng();
         let mut digits: Vec<u32> = (1..=9).collect(); // Vector of digits from 0 to 9
-        digits.shuffle(&mut rng); // Shuffle the digits randomly
+        rng.shuffle(&mut digits); // Shuffle the digits randomly
 
-        // Add 0 at a random position in the remaining digits
-        let zero_index = rng.gen_range(0..=digits.len());
+        // Add 0 at a random position, but never first: a leading zero would
+        // collapse the parsed u32 to fewer digits and leave the secret outside
+        // the assist/solver candidate pool, which also forbids a leading zero.
+        let zero_index = rng.gen_range(1..=digits.len());
         digits.insert(zero_index, 0);
 
         let number_str: String = digits.iter().take(level).map(|&digit| digit.to_string()).collect();
@@ -43,15 +135,27 @@ impl Game {
         Ok(Game { secret_number: number, level })
     }
 
-    fn play(&self) -> Result<GameResult, String> {
+    fn play(&self, assist: bool) -> Result<GameResult, String> {
         let (input_tx, input_rx) = channel();
 
+        // Pool of secrets still consistent with everything the player has seen,
+        // maintained only when the assist is switched on.
+        let mut pool = if assist {
+            Game::candidate_pool(self.level)
+        } else {
+            Vec::new()
+        };
+
+        // `(hits, blows)` of every completed guess, for the shareable grid.
+        let mut history: Vec<(usize, usize)> = Vec::new();
+
         let stop_flag = Arc::new(Mutex::new(false));
         let thread_stop_flag = stop_flag.clone();
 
         let input_thread = Game::start_input_thread(input_tx, thread_stop_flag);
         let mut entered_chars = Vec::with_capacity(self.level);
 
+        let round_start = Instant::now();
         let mut start_time = Instant::now();
         let mut stdout = stdout().into_raw_mode().unwrap();
 
@@ -63,7 +167,8 @@ impl Game {
                 stdout.flush().unwrap();
 
                 *stop_flag.lock().unwrap() = true;
-                return Ok(GameResult::Lose)
+                let summary = Game::render_summary(self.level, &history, round_start.elapsed());
+                return Ok(GameResult::Lose { summary })
             }
 
             if let Ok(key) = input_rx.try_recv() {
@@ -76,15 +181,34 @@ impl Game {
 
             if entered_chars.len() == self.level {
                 let (hits, blows) = self.check_guess(&entered_chars);
+                history.push((hits, blows));
+                attempts += 1;
                 if hits == self.level {
-                    write!(stdout, "\r\n = You won in {} attempts = \n", attempts).unwrap();
+                    let duration = round_start.elapsed();
+                    write!(stdout, "\r\n = You won in {} attempts ({:.1}s) = \n", attempts, duration.as_secs_f64()).unwrap();
                     stdout.flush().unwrap();
 
                     *stop_flag.lock().unwrap() = true;
-                    return Ok(GameResult::Won);
+                    let summary = Game::render_summary(self.level, &history, duration);
+                    return Ok(GameResult::Won { attempts, duration, summary });
                 } else {
-                    attempts += 1;
                     write!(stdout, "\n\r✅ HIT: {}, ❓ BLOW: {}\n\n", hits, blows).unwrap();
+
+                    if assist {
+                        let guess_digits: Vec<u8> = entered_chars
+                            .iter()
+                            .map(|c| c.to_digit(10).unwrap() as u8)
+                            .collect();
+                        pool.retain(|candidate| Game::check_response(candidate, &guess_digits) == (hits, blows));
+                        write!(stdout, "\r💡 {} possible secret(s) remain\r\n", pool.len()).unwrap();
+
+                        if pool.len() <= ASSIST_REVEAL_THRESHOLD {
+                            if let Some((pos, digit)) = Game::safe_digit(&pool) {
+                                write!(stdout, "\r💡 Hint: position {} is {}\r\n", pos + 1, digit).unwrap();
+                            }
+                        }
+                    }
+
                     stdout.flush().unwrap();
 
                     start_time = Instant::now();
@@ -155,6 +279,248 @@ impl Game {
         }
     }
 
+    // Build the full set of valid secrets for a given level: every sequence of
+    // `level` distinct digits, using the same "leading digit logic" as
+    // `Game::new` (the secret is stored as a `u32`, so it never leads with 0).
+    fn candidate_pool(level: usize) -> Vec<Vec<u8>> {
+        let mut pool = Vec::new();
+        let mut current = Vec::with_capacity(level);
+        let mut used = [false; 10];
+        Game::grow_candidates(level, &mut current, &mut used, &mut pool);
+        pool
+    }
+
+    fn grow_candidates(level: usize, current: &mut Vec<u8>, used: &mut [bool; 10], pool: &mut Vec<Vec<u8>>) {
+        if current.len() == level {
+            pool.push(current.clone());
+            return;
+        }
+
+        for digit in 0u8..=9 {
+            if used[digit as usize] {
+                continue;
+            }
+            if current.is_empty() && digit == 0 {
+                continue; // no leading zero, matching the stored u32 secret
+            }
+
+            used[digit as usize] = true;
+            current.push(digit);
+            Game::grow_candidates(level, current, used, pool);
+            current.pop();
+            used[digit as usize] = false;
+        }
+    }
+
+    // Score a distinct-digit guess against a secret the same way `check_guess`
+    // does, but over plain digit slices so the solver can pit candidates
+    // against each other. A guess with repeated digits gets too many blows.
+    fn response(secret: &[u8], guess: &[u8]) -> (usize, usize) {
+        let mut present = [false; 10];
+        for &digit in secret {
+            present[digit as usize] = true;
+        }
+
+        let mut hits = 0;
+        let mut common = 0;
+        for (i, &guess_digit) in guess.iter().enumerate() {
+            if secret[i] == guess_digit {
+                hits += 1;
+            }
+            if present[guess_digit as usize] {
+                common += 1;
+            }
+        }
+
+        (hits, common - hits)
+    }
+
+    // Score `guess` against `secret` exactly the way `check_guess` does, so the
+    // assist pool is pruned with the same feedback the player was shown. Unlike
+    // `response`, this matches `check_guess` when the guess contains repeated
+    // digits (a blow is only counted for a guess digit that is present and not
+    // already a hit), which the interactive input path does not forbid.
+    fn check_response(secret: &[u8], guess: &[u8]) -> (usize, usize) {
+        let mut hits = 0;
+        let mut matched = [false; 10];
+
+        for (i, &secret_digit) in secret.iter().enumerate() {
+            if secret_digit == guess[i] {
+                hits += 1;
+                matched[secret_digit as usize] = true;
+            }
+        }
+
+        let mut blows = 0;
+        for &guess_digit in guess {
+            if !matched[guess_digit as usize] && secret.contains(&guess_digit) {
+                blows += 1;
+            }
+        }
+
+        (hits, blows)
+    }
+
+    // Pack a distinct-digit number into a `u64`: each digit (stored as
+    // `digit + 1`, so an empty lane reads as 0) occupies a nibble in the low
+    // 40 bits, and a precomputed presence bitmask of digits 0–9 sits in bits
+    // 40..50. This lets `fast_response` score a pair without any allocation.
+    fn pack(digits: &[u8]) -> u64 {
+        let mut lanes = 0u64;
+        let mut mask = 0u64;
+        for (i, &digit) in digits.iter().enumerate() {
+            lanes |= (digit as u64 + 1) << (4 * i);
+            mask |= 1u64 << digit;
+        }
+        lanes | (mask << 40)
+    }
+
+    // Allocation-free equivalent of `check_guess` over packed numbers: hits by
+    // comparing nibble lanes, blows from the popcount of the shared presence
+    // bits minus hits.
+    fn fast_response(secret_packed: u64, guess_packed: u64) -> (u8, u8) {
+        const LANES: u64 = (1 << 40) - 1;
+
+        let mut secret = secret_packed & LANES;
+        let mut guess = guess_packed & LANES;
+        let mut hits = 0u8;
+        while secret != 0 || guess != 0 {
+            let secret_lane = secret & 0xF;
+            let guess_lane = guess & 0xF;
+            if secret_lane != 0 && secret_lane == guess_lane {
+                hits += 1;
+            }
+            secret >>= 4;
+            guess >>= 4;
+        }
+
+        let common = ((secret_packed >> 40) & (guess_packed >> 40)).count_ones() as u8;
+        (hits, common - hits)
+    }
+
+    // Knuth-style minimax: pick the candidate whose worst-case feedback
+    // partition is smallest, so we learn the most in the adversarial case. The
+    // inner loop runs over packed numbers via `fast_response` so the whole
+    // pool can be scored against itself in milliseconds.
+    fn best_guess(pool: &[Vec<u8>]) -> Vec<u8> {
+        let packed: Vec<u64> = pool.iter().map(|candidate| Game::pack(candidate)).collect();
+
+        let mut best = 0;
+        let mut best_worst = usize::MAX;
+
+        for (gi, &guess) in packed.iter().enumerate() {
+            let mut buckets: std::collections::HashMap<(u8, u8), usize> =
+                std::collections::HashMap::new();
+            for &candidate in &packed {
+                *buckets.entry(Game::fast_response(candidate, guess)).or_insert(0) += 1;
+            }
+
+            let worst = buckets.values().copied().max().unwrap_or(0);
+            if worst < best_worst {
+                best_worst = worst;
+                best = gi;
+            }
+        }
+
+        pool[best].clone()
+    }
+
+    // Inverse mode: the human keeps a secret and the computer deduces it,
+    // driven only by the `(hits, blows)` feedback the human reports.
+    fn codebreak(level: usize) {
+        let mut pool = Game::candidate_pool(level);
+        // A fixed, valid opening guess (distinct digits, no leading zero).
+        let mut guess: Vec<u8> = (1..=level as u8).collect();
+
+        loop {
+            let rendered: String = guess.iter().map(|d| d.to_string()).collect();
+            println!("\nI have {} candidate(s) left. My guess is: {}", pool.len(), rendered);
+
+            let (hits, blows) = Game::read_feedback(level);
+            if hits == level {
+                println!("\n🤖 Solved it! Your secret was {}.", rendered);
+                return;
+            }
+
+            pool.retain(|candidate| Game::response(candidate, &guess) == (hits, blows));
+            match pool.len() {
+                0 => {
+                    println!("\nThat feedback is inconsistent — no secret matches it. Giving up.");
+                    return;
+                }
+                1 => {
+                    let answer: String = pool[0].iter().map(|d| d.to_string()).collect();
+                    println!("\n🤖 Your secret must be {}.", answer);
+                    return;
+                }
+                _ => guess = Game::best_guess(&pool),
+            }
+        }
+    }
+
+    // Compact, spoiler-free grid of a round: one row per attempt, a filled
+    // marker per hit, a hollow marker per blow, blanks for the rest. The
+    // entered digits are never shown, so the round stays shareable.
+    fn render_summary(level: usize, history: &[(usize, usize)], duration: Duration) -> String {
+        let mut out = format!(
+            "Numbers L{} — {} attempts in {:.1}s\n",
+            level,
+            history.len(),
+            duration.as_secs_f64()
+        );
+
+        for &(hits, blows) in history {
+            for _ in 0..hits {
+                out.push('🟩');
+            }
+            for _ in 0..blows {
+                out.push('🟨');
+            }
+            for _ in 0..level.saturating_sub(hits + blows) {
+                out.push('⬜');
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    // A digit that is fixed in the same position across every remaining
+    // candidate, so it can be revealed without giving the whole secret away.
+    fn safe_digit(pool: &[Vec<u8>]) -> Option<(usize, u8)> {
+        let first = pool.first()?;
+        for pos in 0..first.len() {
+            let digit = first[pos];
+            if pool.iter().all(|candidate| candidate[pos] == digit) {
+                return Some((pos, digit));
+            }
+        }
+        None
+    }
+
+    fn read_feedback(level: usize) -> (usize, usize) {
+        loop {
+            print!("Report feedback as 'hits blows' (e.g. '2 1'): ");
+            stdout().flush().unwrap();
+
+            let mut input = String::new();
+            if stdin().read_line(&mut input).is_err() {
+                continue;
+            }
+
+            let parts: Vec<&str> = input.trim().split_whitespace().collect();
+            if parts.len() != 2 {
+                println!("Please enter two numbers separated by a space.");
+                continue;
+            }
+
+            match (parts[0].parse::<usize>(), parts[1].parse::<usize>()) {
+                (Ok(hits), Ok(blows)) if hits + blows <= level => return (hits, blows),
+                _ => println!("Invalid feedback for level {}.", level),
+            }
+        }
+    }
+
     fn check_guess(&self, guess: &Vec<char>) -> (usize, usize) {
         let secret_digits: Vec<u8> = self.secret_number
             .to_string()
@@ -191,7 +557,27 @@ impl Game {
     }
 }
 
+// Parse an optional `--seed <u64>` flag so a specific round can be replayed.
+fn parse_seed() -> Option<u64> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            return args.next().and_then(|value| value.parse::<u64>().ok());
+        }
+    }
+    None
+}
+
+// Whether a bare flag (e.g. `--assist`) was passed on the command line.
+fn has_flag(name: &str) -> bool {
+    std::env::args().skip(1).any(|arg| arg == name)
+}
+
 fn main() {
+    let seed = parse_seed();
+    // Assist is opt-in so hardcore players keep the unaided game by default.
+    let assist = has_flag("--assist");
+
     let output = Command::new("clear").output().unwrap();
     println!("{}", String::from_utf8_lossy(&output.stdout));
 
@@ -208,6 +594,20 @@ Try to guess the mistery number!
         Keep guessing until you get it right before time runs out!"#
     );
 
+    print!("\n Choose a mode — (1) you guess my number, (2) I guess yours, or 'q' to quit: ");
+    stdout().flush().unwrap();
+    let mut mode_input = String::new();
+    stdin().read_line(&mut mode_input).unwrap();
+
+    let codebreaker = match mode_input.trim() {
+        "q" => {
+            println!("Thanks for playing!");
+            return;
+        }
+        "2" => true,
+        _ => false,
+    };
+
     print!("\n Choose a level between 3 and 10, or enter 'q' to quit: ");
     stdout().flush().unwrap();
     let mut input = String::new();
@@ -226,20 +626,189 @@ Try to guess the mistery number!
         }
     };
 
-    match Game::new(level) {
-        Ok(game) => {
-            match game.play() {
-                Ok(GameResult::Won) => {
-                    println!("\n\r🎉 Congratulations! \r\n")
+    if codebreaker {
+        if let Err(err) = Game::validate_level(level) {
+            println!("Error: {}", err);
+            return;
+        }
+        println!("\nPick a secret {}-digit number with no repeated digits and keep it to yourself.", level);
+        Game::codebreak(level);
+        return;
+    }
+
+    let mut rng: Box<dyn RangeRng> = match seed {
+        Some(seed) => Box::new(SeededRng::new(seed)),
+        None => Box::new(ThreadRangeRng),
+    };
 
+    let mut scoreboard = Scoreboard::new();
+    let mut level = level;
+
+    loop {
+        match Game::new(level, rng.as_mut()) {
+            Ok(game) => {
+                match game.play(assist) {
+                    Ok(result) => {
+                        match &result {
+                            GameResult::Won { summary, .. } => {
+                                println!("\n\r🎉 Congratulations! \r\n");
+                                println!("{}", summary);
+                            }
+                            GameResult::Lose { summary } => {
+                                println!("\n\r You Lose 💣 \r");
+                                println!("{}", summary);
+                            }
+                        }
+                        scoreboard.record(&result);
+                        scoreboard.report();
+                    }
+                    Err(err) => println!("Error: {}", err),
                 }
-                Ok(GameResult::Lose) => {
-                    println!("\n\r You Lose 💣 \r");
+            }
+            Err(err) => {
+                println!("Error: {}", err);
+                break;
+            }
+        }
+
+        match prompt_next_round(level) {
+            NextRound::Replay => {}
+            NextRound::ChangeLevel(new_level) => level = new_level,
+            NextRound::Quit => break,
+        }
+    }
+
+    // The per-round report above already doubles as the cumulative summary, so
+    // quitting must not print it a second time.
+    println!("\nThanks for playing!");
+}
+
+enum NextRound {
+    Replay,
+    ChangeLevel(usize),
+    Quit,
+}
+
+// Ask the player what to do after a finished round.
+fn prompt_next_round(level: usize) -> NextRound {
+    loop {
+        print!("\nPlay again? (r) replay level {}, (c) change level, (q) quit: ", level);
+        stdout().flush().unwrap();
+
+        let mut input = String::new();
+        stdin().read_line(&mut input).unwrap();
+
+        match input.trim() {
+            "r" => return NextRound::Replay,
+            "q" => return NextRound::Quit,
+            "c" => {
+                print!("Choose a level between 3 and 9: ");
+                stdout().flush().unwrap();
+                let mut level_input = String::new();
+                stdin().read_line(&mut level_input).unwrap();
+                match level_input.trim().parse::<usize>() {
+                    Ok(new_level) if Game::validate_level(new_level).is_ok() => {
+                        return NextRound::ChangeLevel(new_level)
+                    }
+                    _ => println!("Invalid level."),
                 }
-                Err(err) => println!("Error: {}", err),
             }
-        },
-        Err(err) => println!("Error: {}", err),
+            _ => println!("Please enter 'r', 'c', or 'q'."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_rng_is_reproducible() {
+        let mut a = SeededRng::new(42);
+        let mut b = SeededRng::new(42);
+        let first = Game::new(5, &mut a).unwrap();
+        let second = Game::new(5, &mut b).unwrap();
+        assert_eq!(first.secret_number, second.secret_number);
+    }
+
+    #[test]
+    fn seeded_secret_is_valid() {
+        // Distinct digits, exactly `level` of them, and no leading zero.
+        for seed in 0..50u64 {
+            let mut rng = SeededRng::new(seed);
+            let game = Game::new(4, &mut rng).unwrap();
+            let digits: Vec<char> = game.secret_number.to_string().chars().collect();
+            assert_eq!(digits.len(), 4, "seed {seed} lost a digit");
+            assert_ne!(digits[0], '0');
+            let mut seen = digits.clone();
+            seen.sort();
+            seen.dedup();
+            assert_eq!(seen.len(), 4, "seed {seed} repeated a digit");
+        }
+    }
+
+    #[test]
+    fn candidate_pool_has_no_leading_zero_and_distinct_digits() {
+        let pool = Game::candidate_pool(3);
+        // 9 choices for the first digit, then 9 * 8 for the rest.
+        assert_eq!(pool.len(), 9 * 9 * 8);
+        for candidate in &pool {
+            assert_eq!(candidate.len(), 3);
+            assert_ne!(candidate[0], 0);
+            let mut seen = candidate.clone();
+            seen.sort();
+            seen.dedup();
+            assert_eq!(seen.len(), 3);
+        }
+    }
+
+    #[test]
+    fn scorers_match_on_a_known_pair() {
+        let secret = [1u8, 2, 3];
+        let guess = [1u8, 3, 4];
+        assert_eq!(Game::response(&secret, &guess), (1, 1));
+        assert_eq!(Game::check_response(&secret, &guess), (1, 1));
+        assert_eq!(
+            Game::fast_response(Game::pack(&secret), Game::pack(&guess)),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn all_scorers_agree_on_distinct_digit_inputs() {
+        // The four scoring paths (`check_guess`, `check_response`, `response`,
+        // `fast_response`) must return identical `(hits, blows)` whenever the
+        // guess has distinct digits, which is the only regime the solver uses.
+        let pool = Game::candidate_pool(4);
+        for secret in pool.iter().step_by(37) {
+            for guess in pool.iter().step_by(53) {
+                let expected = Game::check_response(secret, guess);
+
+                let secret_u32: u32 = secret
+                    .iter()
+                    .fold(0, |acc, &d| acc * 10 + d as u32);
+                let game = Game { secret_number: secret_u32, level: 4 };
+                let guess_chars: Vec<char> = guess
+                    .iter()
+                    .map(|&d| char::from_digit(d as u32, 10).unwrap())
+                    .collect();
+
+                assert_eq!(game.check_guess(&guess_chars), expected);
+                assert_eq!(Game::response(secret, guess), expected);
+                assert_eq!(
+                    Game::fast_response(Game::pack(secret), Game::pack(guess)),
+                    (expected.0 as u8, expected.1 as u8)
+                );
+            }
+        }
     }
 
+    #[test]
+    fn check_response_handles_repeated_guess_digits() {
+        // `response` over-counts blows on repeats; `check_response` mirrors
+        // `check_guess` (secret 123 vs guess 112 -> one hit, one blow).
+        let secret = [1u8, 2, 3];
+        let guess = [1u8, 1, 2];
+        assert_eq!(Game::check_response(&secret, &guess), (1, 1));
+    }
 }